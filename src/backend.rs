@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+use crate::RespFrame;
+
+#[derive(Debug, Clone, Default)]
+pub struct Backend(Arc<BackendInner>);
+
+#[derive(Debug, Default)]
+pub struct BackendInner {
+    hmap: RwLock<HashMap<String, HashMap<String, RespFrame>>>,
+}
+
+impl Deref for Backend {
+    type Target = BackendInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Backend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hset(&self, key: String, field: String, value: RespFrame) {
+        let mut hmap = self.hmap.write().unwrap();
+        hmap.entry(key).or_default().insert(field, value);
+    }
+
+    pub fn hget(&self, key: &str, field: &str) -> Option<RespFrame> {
+        let hmap = self.hmap.read().unwrap();
+        hmap.get(key).and_then(|m| m.get(field).cloned())
+    }
+}
@@ -0,0 +1,211 @@
+use anyhow::Result;
+use bytes::BytesMut;
+
+use crate::{BulkString, RespArray, RespDecode, RespEncode, RespError, RespFrame};
+
+const BUF_CAP: usize = 4096;
+
+// Assemble a command frame from its parts as an array of bulk strings,
+// e.g. `["get", "hello"]` becomes `*2\r\n$3\r\nget\r\n$5\r\nhello\r\n`.
+fn command(parts: impl IntoIterator<Item = BulkString>) -> RespArray {
+    RespArray::new(
+        parts
+            .into_iter()
+            .map(RespFrame::BulkString)
+            .collect::<Vec<_>>(),
+        false,
+    )
+}
+
+// Blocking client: serialize a command, write it to the connection, and block
+// until a full `RespFrame` has been read back.
+pub trait SyncClient {
+    fn send_and_confirm(&self, cmd: impl Into<RespArray>) -> Result<RespFrame>;
+
+    fn get(&self, key: impl Into<BulkString>) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("get"), key.into()]))
+    }
+
+    fn set(&self, key: impl Into<BulkString>, value: impl Into<BulkString>) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("set"), key.into(), value.into()]))
+    }
+
+    fn echo(&self, msg: impl Into<BulkString>) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("echo"), msg.into()]))
+    }
+
+    fn sadd(&self, key: impl Into<BulkString>, member: impl Into<BulkString>) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("sadd"), key.into(), member.into()]))
+    }
+
+    fn sismember(
+        &self,
+        key: impl Into<BulkString>,
+        member: impl Into<BulkString>,
+    ) -> Result<RespFrame> {
+        self.send_and_confirm(command([
+            BulkString::from("sismember"),
+            key.into(),
+            member.into(),
+        ]))
+    }
+}
+
+// Fire-and-forget plus await-able confirm over an async connection.
+//
+// The returned futures are intentionally left without a `Send` bound: a client
+// drives a single connection and is awaited on the task that owns it, so we opt
+// out of the `async_fn_in_trait` lint rather than threading `impl Future + Send`
+// through every method.
+#[allow(async_fn_in_trait)]
+pub trait AsyncClient {
+    async fn send(&self, cmd: impl Into<RespArray>) -> Result<()>;
+    async fn send_and_confirm(&self, cmd: impl Into<RespArray>) -> Result<RespFrame>;
+
+    async fn get(&self, key: impl Into<BulkString>) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("get"), key.into()]))
+            .await
+    }
+
+    async fn set(
+        &self,
+        key: impl Into<BulkString>,
+        value: impl Into<BulkString>,
+    ) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("set"), key.into(), value.into()]))
+            .await
+    }
+
+    async fn echo(&self, msg: impl Into<BulkString>) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("echo"), msg.into()]))
+            .await
+    }
+
+    async fn sadd(
+        &self,
+        key: impl Into<BulkString>,
+        member: impl Into<BulkString>,
+    ) -> Result<RespFrame> {
+        self.send_and_confirm(command([BulkString::from("sadd"), key.into(), member.into()]))
+            .await
+    }
+
+    async fn sismember(
+        &self,
+        key: impl Into<BulkString>,
+        member: impl Into<BulkString>,
+    ) -> Result<RespFrame> {
+        self.send_and_confirm(command([
+            BulkString::from("sismember"),
+            key.into(),
+            member.into(),
+        ]))
+        .await
+    }
+}
+
+// Read one full frame out of `buf`, pulling more bytes from `read_more` whenever
+// the incremental decoder reports `NotComplete`.
+fn decode_frame(
+    buf: &mut BytesMut,
+    mut read_more: impl FnMut(&mut BytesMut) -> Result<usize>,
+) -> Result<RespFrame> {
+    loop {
+        match RespFrame::decode(buf) {
+            Ok(frame) => return Ok(frame),
+            Err(RespError::NotComplete) => {
+                if read_more(buf)? == 0 {
+                    return Err(RespError::NotComplete.into());
+                }
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod sync_impl {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    impl SyncClient for TcpStream {
+        fn send_and_confirm(&self, cmd: impl Into<RespArray>) -> Result<RespFrame> {
+            let mut stream = self;
+            stream.write_all(&cmd.into().encode())?;
+
+            let mut buf = BytesMut::with_capacity(BUF_CAP);
+            let mut chunk = [0u8; BUF_CAP];
+            decode_frame(&mut buf, |buf| {
+                let n = stream.read(&mut chunk)?;
+                buf.extend_from_slice(&chunk[..n]);
+                Ok(n)
+            })
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod async_impl {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+    use tokio::sync::Mutex;
+
+    // Shares a single `TcpStream` behind a `Mutex` so `&self` methods can both
+    // write a request and read its confirmation without interleaving.
+    pub struct AsyncRedisClient {
+        stream: Mutex<TcpStream>,
+    }
+
+    impl AsyncRedisClient {
+        pub fn new(stream: TcpStream) -> Self {
+            Self {
+                stream: Mutex::new(stream),
+            }
+        }
+    }
+
+    impl AsyncClient for AsyncRedisClient {
+        async fn send(&self, cmd: impl Into<RespArray>) -> Result<()> {
+            let mut stream = self.stream.lock().await;
+            stream.write_all(&cmd.into().encode()).await?;
+            Ok(())
+        }
+
+        async fn send_and_confirm(&self, cmd: impl Into<RespArray>) -> Result<RespFrame> {
+            let mut stream = self.stream.lock().await;
+            stream.write_all(&cmd.into().encode()).await?;
+
+            let mut buf = BytesMut::with_capacity(BUF_CAP);
+            let mut chunk = [0u8; BUF_CAP];
+            loop {
+                match RespFrame::decode(&mut buf) {
+                    Ok(frame) => return Ok(frame),
+                    Err(RespError::NotComplete) => {
+                        let n = stream.read(&mut chunk).await?;
+                        if n == 0 {
+                            return Err(RespError::NotComplete.into());
+                        }
+                        buf.extend_from_slice(&chunk[..n]);
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use async_impl::AsyncRedisClient;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_builds_bulk_string_array() {
+        let frame: RespFrame = command([BulkString::from("get"), BulkString::from("hello")]).into();
+        assert_eq!(frame.encode(), b"*2\r\n$3\r\nget\r\n$5\r\nhello\r\n");
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::LazyLock;
+
+use crate::{Backend, RespArray, RespFrame};
+
+pub mod echo;
+pub mod set;
+
+pub static RESP_OK: LazyLock<RespFrame> =
+    LazyLock::new(|| RespFrame::SimpleString("OK".to_string()));
+
+pub trait CommandExecutor {
+    fn execute(self, backend: &Backend) -> RespFrame;
+}
+
+#[derive(Debug)]
+pub enum CommandError {
+    InvalidCommand(String),
+    InvalidArgument(String),
+    Utf8Error(String),
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::InvalidCommand(s) => write!(f, "Invalid command: {}", s),
+            CommandError::InvalidArgument(s) => write!(f, "Invalid argument: {}", s),
+            CommandError::Utf8Error(s) => write!(f, "UTF-8 error: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<std::string::FromUtf8Error> for CommandError {
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        CommandError::Utf8Error(e.to_string())
+    }
+}
+
+// Validate that `value` starts with the given command name(s) and carries
+// exactly `n_args` additional arguments.
+pub fn validate_command(
+    value: &RespArray,
+    names: &[&str],
+    n_args: usize,
+) -> Result<(), CommandError> {
+    if value.len() != names.len() + n_args {
+        return Err(CommandError::InvalidArgument(format!(
+            "{} command must have exactly {} argument(s)",
+            names.join(" "),
+            n_args
+        )));
+    }
+
+    for (i, name) in names.iter().enumerate() {
+        match &value[i] {
+            RespFrame::BulkString(cmd) => {
+                if !cmd.as_bytes().eq_ignore_ascii_case(name.as_bytes()) {
+                    return Err(CommandError::InvalidCommand(format!(
+                        "Invalid command: expected {}, got {}",
+                        name,
+                        String::from_utf8_lossy(cmd.as_bytes())
+                    )));
+                }
+            }
+            _ => {
+                return Err(CommandError::InvalidCommand(
+                    "Command must have a BulkString as the first argument".to_string(),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Consume `value`, dropping the leading command name(s) and returning the
+// remaining argument frames.
+pub fn extract_args(value: RespArray, start: usize) -> Result<Vec<RespFrame>, CommandError> {
+    Ok(value.0.into_iter().skip(start).collect())
+}
@@ -43,7 +43,7 @@ impl TryFrom<RespArray> for SAdd {
             _ => None,
         };
         let fields = args.collect::<Vec<_>>();
-        if fields.is_empty() || fields.len() < 1 {
+        if fields.is_empty() {
             Err(CommandError::InvalidArgument("Invalid key".to_string()))
         } else {
             Ok(SAdd {
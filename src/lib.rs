@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// The codec only needs `alloc`; pull `Vec`/`String`/`format!` from it when the
+// default `std` feature is off so the crate can be embedded in no_std contexts
+// that bring their own I/O layer.
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+pub mod resp;
+pub use resp::*;
+
+// Commands and the client need `std` networking / synchronisation primitives,
+// so they stay behind the default `std` feature.
+#[cfg(feature = "std")]
+mod backend;
+#[cfg(feature = "std")]
+pub use backend::Backend;
+
+#[cfg(feature = "std")]
+pub mod cmd;
+
+#[cfg(feature = "std")]
+pub mod client;
@@ -1,7 +1,10 @@
-use std::ops::Deref;
+use core::ops::Deref;
 
 use bytes::{Buf, BytesMut};
 
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
 use crate::{BulkString, RespDecode, RespEncode, RespError, RespFrame};
 
 use super::{calc_total_length, parse_length, BUF_CAP, CRLF_LEN, NULL_ARRAY};
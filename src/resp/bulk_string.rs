@@ -1,7 +1,14 @@
 use super::{parse_length, CRLF_LEN, NULL_BULK_STRING};
 use crate::{RespDecode, RespEncode, RespError};
 use bytes::{Buf, BytesMut};
-use std::ops::Deref;
+use core::ops::Deref;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub struct BulkString(pub(crate) Vec<u8>);
@@ -61,6 +68,93 @@ impl BulkString {
     pub fn new(s: impl Into<Vec<u8>>) -> Self {
         BulkString(s.into())
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    // Decode a base64 payload (standard alphabet) into a `BulkString`. Trailing
+    // padding is optional so we interoperate with loose encoders.
+    pub fn from_base64(input: impl AsRef<[u8]>) -> Result<Self, RespError> {
+        Ok(BulkString(base64_decode(input.as_ref())?))
+    }
+
+    // Encode the underlying bytes as standard base64 with padding.
+    pub fn to_base64(&self) -> String {
+        base64_encode(&self.0)
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as usize;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as usize;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as usize;
+        out.push(BASE64_ALPHABET[b0 >> 2] as char);
+        out.push(BASE64_ALPHABET[(b0 & 0x03) << 4 | b1 >> 4] as char);
+        if chunk.len() > 1 {
+            out.push(BASE64_ALPHABET[(b1 & 0x0f) << 2 | b2 >> 6] as char);
+        } else {
+            out.push('=');
+        }
+        if chunk.len() > 2 {
+            out.push(BASE64_ALPHABET[b2 & 0x3f] as char);
+        } else {
+            out.push('=');
+        }
+    }
+    out
+}
+
+fn base64_value(byte: u8) -> Result<u8, RespError> {
+    match byte {
+        b'A'..=b'Z' => Ok(byte - b'A'),
+        b'a'..=b'z' => Ok(byte - b'a' + 26),
+        b'0'..=b'9' => Ok(byte - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(RespError::InvalidEncoding(format!(
+            "invalid base64 byte: {}",
+            byte
+        ))),
+    }
+}
+
+fn base64_decode(input: &[u8]) -> Result<Vec<u8>, RespError> {
+    // Padding-indifferent: only *trailing* '=' is optional, so input with or
+    // without padding decodes identically. Any '=' that is not part of the
+    // trailing run stays in the stream and is rejected by `base64_value` below,
+    // so misplaced padding like "YQ==Yg==" surfaces as `InvalidEncoding`.
+    let end = input
+        .iter()
+        .rposition(|&b| b != b'=')
+        .map_or(0, |pos| pos + 1);
+    let input: &[u8] = &input[..end];
+
+    let mut out = Vec::with_capacity(input.len() / 4 * 3);
+    for chunk in input.chunks(4) {
+        if chunk.len() == 1 {
+            return Err(RespError::InvalidEncoding(
+                "invalid base64 length".to_string(),
+            ));
+        }
+        let vals: Vec<u8> = chunk
+            .iter()
+            .map(|&b| base64_value(b))
+            .collect::<Result<_, _>>()?;
+        out.push(vals[0] << 2 | vals[1] >> 4);
+        if vals.len() > 2 {
+            out.push(vals[1] << 4 | vals[2] >> 2);
+        }
+        if vals.len() > 3 {
+            out.push(vals[2] << 6 | vals[3]);
+        }
+    }
+    Ok(out)
 }
 
 impl AsRef<[u8]> for BulkString {
@@ -69,6 +163,85 @@ impl AsRef<[u8]> for BulkString {
     }
 }
 
+// Compare a `BulkString` against common byte-backed types by its underlying
+// bytes, in both directions, so callers can write `key == "echo"` instead of
+// round-tripping through `String::from_utf8`.
+macro_rules! impl_partial_eq {
+    ($lhs:ty, $rhs:ty) => {
+        impl PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                let o: &[u8] = other.as_ref();
+                PartialEq::eq(self.as_bytes(), o)
+            }
+        }
+
+        impl PartialEq<$lhs> for $rhs {
+            fn eq(&self, other: &$lhs) -> bool {
+                let s: &[u8] = self.as_ref();
+                PartialEq::eq(s, other.as_bytes())
+            }
+        }
+    };
+}
+
+macro_rules! impl_partial_ord {
+    ($lhs:ty, $rhs:ty) => {
+        impl PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, other: &$rhs) -> Option<core::cmp::Ordering> {
+                let o: &[u8] = other.as_ref();
+                PartialOrd::partial_cmp(self.as_bytes(), o)
+            }
+        }
+
+        impl PartialOrd<$lhs> for $rhs {
+            fn partial_cmp(&self, other: &$lhs) -> Option<core::cmp::Ordering> {
+                let s: &[u8] = self.as_ref();
+                PartialOrd::partial_cmp(s, other.as_bytes())
+            }
+        }
+    };
+}
+
+impl_partial_eq!(BulkString, &str);
+impl_partial_eq!(BulkString, str);
+impl_partial_eq!(BulkString, &[u8]);
+impl_partial_eq!(BulkString, Vec<u8>);
+impl_partial_eq!(BulkString, String);
+
+impl_partial_ord!(BulkString, &str);
+impl_partial_ord!(BulkString, str);
+impl_partial_ord!(BulkString, &[u8]);
+impl_partial_ord!(BulkString, Vec<u8>);
+impl_partial_ord!(BulkString, String);
+
+impl<const N: usize> PartialEq<[u8; N]> for BulkString {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        let o: &[u8] = other.as_ref();
+        PartialEq::eq(self.as_bytes(), o)
+    }
+}
+
+impl<const N: usize> PartialEq<BulkString> for [u8; N] {
+    fn eq(&self, other: &BulkString) -> bool {
+        let s: &[u8] = self.as_ref();
+        PartialEq::eq(s, other.as_bytes())
+    }
+}
+
+impl<const N: usize> PartialOrd<[u8; N]> for BulkString {
+    fn partial_cmp(&self, other: &[u8; N]) -> Option<core::cmp::Ordering> {
+        let o: &[u8] = other.as_ref();
+        PartialOrd::partial_cmp(self.as_bytes(), o)
+    }
+}
+
+impl<const N: usize> PartialOrd<BulkString> for [u8; N] {
+    fn partial_cmp(&self, other: &BulkString) -> Option<core::cmp::Ordering> {
+        let s: &[u8] = self.as_ref();
+        PartialOrd::partial_cmp(s, other.as_bytes())
+    }
+}
+
 impl Deref for BulkString {
     type Target = Vec<u8>;
 
@@ -139,6 +312,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_bulk_string_cross_type_eq() {
+        let s = BulkString::new(b"echo");
+        assert_eq!(s, "echo");
+        assert_eq!("echo", s);
+        assert_eq!(s, b"echo"[..].to_vec());
+        assert_eq!(s, *b"echo");
+        assert!(s < "echp");
+        assert!("echa" < s);
+    }
+
+    #[test]
+    fn test_bulk_string_base64_round_trip() -> Result<()> {
+        let s = BulkString::new(b"hello world".to_vec());
+        let encoded = s.to_base64();
+        assert_eq!(encoded, "aGVsbG8gd29ybGQ=");
+        assert_eq!(BulkString::from_base64(&encoded)?, s);
+        // Padding-indifferent: the same payload without padding decodes too.
+        assert_eq!(BulkString::from_base64("aGVsbG8gd29ybGQ")?, s);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_string_from_base64_invalid() {
+        let ret = BulkString::from_base64("not base64!");
+        assert!(matches!(ret, Err(RespError::InvalidEncoding(_))));
+        // Embedded padding is malformed, not silently truncated to "a".
+        let ret = BulkString::from_base64("YQ==Yg==");
+        assert!(matches!(ret, Err(RespError::InvalidEncoding(_))));
+    }
+
     #[test]
     fn test_null_bulk_string_decode() -> Result<()> {
         let mut buf = BytesMut::new();
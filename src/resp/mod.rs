@@ -0,0 +1,191 @@
+mod array;
+mod bulk_string;
+
+use bytes::BytesMut;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+pub use array::RespArray;
+pub use bulk_string::BulkString;
+
+pub(crate) const BUF_CAP: usize = 4096;
+pub(crate) const CRLF_LEN: usize = 2;
+pub(crate) const NULL_ARRAY: &[u8] = b"*-1\r\n";
+pub(crate) const NULL_BULK_STRING: &[u8] = b"$-1\r\n";
+
+pub trait RespEncode {
+    fn encode(self) -> Vec<u8>;
+}
+
+pub trait RespDecode: Sized {
+    const PREFIX: &'static str;
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError>;
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RespError {
+    InvalidFrame(String),
+    InvalidFrameType(String),
+    InvalidFrameLength(isize),
+    InvalidEncoding(String),
+    NotComplete,
+}
+
+impl core::fmt::Display for RespError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RespError::InvalidFrame(s) => write!(f, "Invalid frame: {}", s),
+            RespError::InvalidFrameType(s) => write!(f, "Invalid frame type: {}", s),
+            RespError::InvalidFrameLength(n) => write!(f, "Invalid frame length: {}", n),
+            RespError::InvalidEncoding(s) => write!(f, "Invalid encoding: {}", s),
+            RespError::NotComplete => write!(f, "Frame is not complete"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RespError {}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum RespFrame {
+    SimpleString(String),
+    Integer(i64),
+    BulkString(BulkString),
+    Array(RespArray),
+}
+
+impl RespEncode for RespFrame {
+    fn encode(self) -> Vec<u8> {
+        match self {
+            RespFrame::SimpleString(s) => format!("+{}\r\n", s).into_bytes(),
+            RespFrame::Integer(n) => format!(":{}\r\n", n).into_bytes(),
+            RespFrame::BulkString(b) => b.encode(),
+            RespFrame::Array(a) => a.encode(),
+        }
+    }
+}
+
+impl RespDecode for RespFrame {
+    const PREFIX: &'static str = "";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let prefix = *buf.first().ok_or(RespError::NotComplete)?;
+        match prefix {
+            b'+' => Ok(RespFrame::SimpleString(decode_simple_string(buf)?)),
+            b':' => Ok(RespFrame::Integer(decode_integer(buf)?)),
+            b'$' => Ok(BulkString::decode(buf)?.into()),
+            b'*' => Ok(RespArray::decode(buf)?.into()),
+            _ => Err(RespError::InvalidFrameType(format!(
+                "unexpected frame type: {}",
+                prefix as char
+            ))),
+        }
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let prefix = *buf.first().ok_or(RespError::NotComplete)?;
+        match prefix {
+            b'$' => BulkString::expect_length(buf),
+            b'*' => RespArray::expect_length(buf),
+            b'+' | b':' => {
+                let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+                Ok(end + CRLF_LEN)
+            }
+            _ => Err(RespError::InvalidFrameType(format!(
+                "unexpected frame type: {}",
+                prefix as char
+            ))),
+        }
+    }
+}
+
+impl From<BulkString> for RespFrame {
+    fn from(s: BulkString) -> Self {
+        RespFrame::BulkString(s)
+    }
+}
+
+impl From<RespArray> for RespFrame {
+    fn from(s: RespArray) -> Self {
+        RespFrame::Array(s)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for RespFrame {
+    fn from(s: &[u8; N]) -> Self {
+        RespFrame::BulkString(BulkString::new(s.to_vec()))
+    }
+}
+
+// Find the position of the `nth` CRLF in `buf`, returning the index of its `\r`.
+fn find_crlf(buf: &[u8], nth: usize) -> Option<usize> {
+    let mut count = 0;
+    for i in 1..buf.len() {
+        if buf[i - 1] == b'\r' && buf[i] == b'\n' {
+            count += 1;
+            if count == nth {
+                return Some(i - 1);
+            }
+        }
+    }
+    None
+}
+
+// Parse the `$<len>`/`*<len>` header, returning the index of the length's `\r`
+// and the parsed length.
+pub fn parse_length(buf: &[u8], prefix: &str) -> Result<(usize, usize), RespError> {
+    if !buf.starts_with(prefix.as_bytes()) {
+        return Err(RespError::InvalidFrameType(format!(
+            "expect: {}, got: {:?}",
+            prefix, buf
+        )));
+    }
+    let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+    let s = String::from_utf8_lossy(&buf[prefix.len()..end]);
+    let len = s
+        .parse()
+        .map_err(|_| RespError::InvalidFrame(format!("invalid length: {}", s)))?;
+    Ok((end, len))
+}
+
+// Total on-the-wire length of a framed value, so decoders can bail out early
+// with `NotComplete` when the buffer does not yet hold the whole frame.
+pub fn calc_total_length(
+    buf: &[u8],
+    end: usize,
+    len: usize,
+    prefix: &str,
+) -> Result<usize, RespError> {
+    let mut total = end + CRLF_LEN;
+    match prefix {
+        "*" => {
+            let mut data = &buf[total..];
+            for _ in 0..len {
+                let l = RespFrame::expect_length(data)?;
+                data = data.get(l..).ok_or(RespError::NotComplete)?;
+                total += l;
+            }
+            Ok(total)
+        }
+        _ => Ok(total + len + CRLF_LEN),
+    }
+}
+
+fn decode_simple_string(buf: &mut BytesMut) -> Result<String, RespError> {
+    let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+    let line = buf.split_to(end + CRLF_LEN);
+    Ok(String::from_utf8_lossy(&line[1..end]).to_string())
+}
+
+fn decode_integer(buf: &mut BytesMut) -> Result<i64, RespError> {
+    let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+    let line = buf.split_to(end + CRLF_LEN);
+    let s = String::from_utf8_lossy(&line[1..end]);
+    s.parse()
+        .map_err(|_| RespError::InvalidFrame(format!("invalid integer: {}", s)))
+}